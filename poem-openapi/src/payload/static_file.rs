@@ -20,35 +20,104 @@ impl StaticFile {
 
 impl ApiResponse for StaticFile {
     fn meta() -> MetaResponses {
+        let etag_header = MetaHeader {
+            name: "ETag",
+            description: None,
+            required: false,
+            schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+        };
+        let last_modified_header = MetaHeader {
+            name: "Last-Modified",
+            description: None,
+            required: false,
+            schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
+                "string",
+                "date-time",
+            ))),
+        };
+        let content_disposition_header = MetaHeader {
+            name: "Content-Disposition",
+            description: Some("Present when an attachment filename or disposition was set"),
+            required: false,
+            schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+        };
+
         MetaResponses {
-            responses: vec![MetaResponse {
-                description: "File content",
-                status: None,
-                content: vec![MetaMediaType {
-                    content_type: "application/octet-stream",
-                    schema: MetaSchemaRef::Inline(Box::new(MetaSchema {
-                        format: Some("binary"),
-                        ..MetaSchema::new("string")
-                    })),
-                }],
-                headers: vec![
-                    MetaHeader {
-                        name: "ETag",
+            responses: vec![
+                MetaResponse {
+                    description: "File content",
+                    status: None,
+                    content: vec![MetaMediaType {
+                        content_type: "application/octet-stream",
+                        schema: MetaSchemaRef::Inline(Box::new(MetaSchema {
+                            format: Some("binary"),
+                            ..MetaSchema::new("string")
+                        })),
+                    }],
+                    headers: vec![
+                        etag_header.clone(),
+                        last_modified_header.clone(),
+                        content_disposition_header.clone(),
+                    ],
+                },
+                MetaResponse {
+                    description: "Partial file content",
+                    status: Some(206),
+                    content: vec![MetaMediaType {
+                        content_type: "application/octet-stream",
+                        schema: MetaSchemaRef::Inline(Box::new(MetaSchema {
+                            format: Some("binary"),
+                            ..MetaSchema::new("string")
+                        })),
+                    }],
+                    headers: vec![
+                        etag_header.clone(),
+                        last_modified_header.clone(),
+                        content_disposition_header.clone(),
+                        MetaHeader {
+                            name: "Content-Range",
+                            description: None,
+                            required: true,
+                            schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                        },
+                        MetaHeader {
+                            name: "Content-Length",
+                            description: None,
+                            required: true,
+                            schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("integer"))),
+                        },
+                        MetaHeader {
+                            name: "Accept-Ranges",
+                            description: None,
+                            required: true,
+                            schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                        },
+                    ],
+                },
+                MetaResponse {
+                    description: "File not modified",
+                    status: Some(304),
+                    content: vec![],
+                    headers: vec![etag_header.clone(), last_modified_header.clone()],
+                },
+                MetaResponse {
+                    description: "Precondition failed",
+                    status: Some(412),
+                    content: vec![],
+                    headers: vec![],
+                },
+                MetaResponse {
+                    description: "Range not satisfiable",
+                    status: Some(416),
+                    content: vec![],
+                    headers: vec![MetaHeader {
+                        name: "Content-Range",
                         description: None,
-                        required: false,
+                        required: true,
                         schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
-                    },
-                    MetaHeader {
-                        name: "Last-Modified",
-                        description: None,
-                        required: false,
-                        schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
-                            "string",
-                            "date-time",
-                        ))),
-                    },
-                ],
-            }],
+                    }],
+                },
+            ],
         }
     }
 
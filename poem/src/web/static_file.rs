@@ -1,15 +1,20 @@
 use std::{
     fs::Metadata,
-    path::Path,
+    io::{Seek, SeekFrom},
+    path::{Path, PathBuf},
     str::FromStr,
     time::{SystemTime, UNIX_EPOCH},
 };
 
+#[cfg(feature = "i18n")]
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
 use headers::{ETag, HeaderMapExt, IfMatch, IfModifiedSince, IfNoneMatch, IfUnmodifiedSince};
 use http::{header, StatusCode};
 use httpdate::HttpDate;
 use mime::Mime;
-use tokio::fs::File;
+use tokio::{fs::File, io::AsyncReadExt};
+#[cfg(feature = "i18n")]
+use unic_langid::LanguageIdentifier;
 
 use crate::{error::StaticFileError, Body, FromRequest, Request, RequestBody, Response, Result};
 
@@ -19,6 +24,39 @@ pub struct StaticFile {
     if_unmodified_since: Option<IfUnmodifiedSince>,
     if_none_match: Option<IfNoneMatch>,
     if_modified_since: Option<IfModifiedSince>,
+    range: Option<String>,
+    if_range: Option<String>,
+    content_disposition_type: Option<ContentDispositionType>,
+    attachment_filename: Option<String>,
+    precompressed: bool,
+    accept_encoding: Option<String>,
+    content_type: Option<Mime>,
+    status: Option<StatusCode>,
+    cache_control: Option<String>,
+    etag_enabled: bool,
+    last_modified_enabled: bool,
+    #[cfg(feature = "i18n")]
+    language_negotiation: bool,
+    #[cfg(feature = "i18n")]
+    accept_language: Option<String>,
+}
+
+/// The `Content-Disposition` type to send alongside a static file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentDispositionType {
+    /// Display the content inline, e.g. rendered in the browser.
+    Inline,
+    /// Prompt the browser to download the content as a file.
+    Attachment,
+}
+
+impl ContentDispositionType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ContentDispositionType::Inline => "inline",
+            ContentDispositionType::Attachment => "attachment",
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -29,11 +67,126 @@ impl<'a> FromRequest<'a> for StaticFile {
             if_unmodified_since: req.headers().typed_get::<IfUnmodifiedSince>(),
             if_none_match: req.headers().typed_get::<IfNoneMatch>(),
             if_modified_since: req.headers().typed_get::<IfModifiedSince>(),
+            range: header_as_str(req, header::RANGE),
+            if_range: header_as_str(req, header::IF_RANGE),
+            content_disposition_type: None,
+            attachment_filename: None,
+            precompressed: false,
+            accept_encoding: header_as_str(req, header::ACCEPT_ENCODING),
+            content_type: None,
+            status: None,
+            cache_control: None,
+            etag_enabled: true,
+            last_modified_enabled: true,
+            #[cfg(feature = "i18n")]
+            language_negotiation: false,
+            #[cfg(feature = "i18n")]
+            accept_language: header_as_str(req, header::ACCEPT_LANGUAGE),
         })
     }
 }
 
+fn header_as_str(req: &Request, name: header::HeaderName) -> Option<String> {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
+
 impl StaticFile {
+    /// Overrides the `Content-Disposition` type, instead of guessing it from
+    /// the file's MIME type.
+    #[must_use]
+    pub fn with_content_disposition_type(self, ty: ContentDispositionType) -> Self {
+        Self {
+            content_disposition_type: Some(ty),
+            ..self
+        }
+    }
+
+    /// Sets the filename to send in the `Content-Disposition` header.
+    #[must_use]
+    pub fn with_attachment_filename(self, filename: impl Into<String>) -> Self {
+        Self {
+            attachment_filename: Some(filename.into()),
+            ..self
+        }
+    }
+
+    /// Enables serving pre-compressed sibling files (`<path>.br`, `<path>.gz`,
+    /// `<path>.zst`) when the client's `Accept-Encoding` allows it.
+    #[must_use]
+    pub fn with_precompressed(self, enable: bool) -> Self {
+        Self {
+            precompressed: enable,
+            ..self
+        }
+    }
+
+    /// Overrides the guessed `Content-Type`.
+    #[must_use]
+    pub fn with_content_type(self, content_type: Mime) -> Self {
+        Self {
+            content_type: Some(content_type),
+            ..self
+        }
+    }
+
+    /// Overrides the `200 OK` status of a full-body response. Useful for SPA
+    /// fallbacks that want to serve `index.html` with a `404` status, for
+    /// example. `206`, `304`, `412` and `416` responses keep their status.
+    #[must_use]
+    pub fn with_status(self, status: StatusCode) -> Self {
+        Self {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    /// Overrides the `Cache-Control` header value, instead of `public`.
+    #[must_use]
+    pub fn with_cache_control(self, cache_control: impl Into<String>) -> Self {
+        Self {
+            cache_control: Some(cache_control.into()),
+            ..self
+        }
+    }
+
+    /// Disables computing and sending the `ETag` header.
+    #[must_use]
+    pub fn disable_etag(self) -> Self {
+        Self {
+            etag_enabled: false,
+            ..self
+        }
+    }
+
+    /// Disables computing and sending the `Last-Modified` header.
+    #[must_use]
+    pub fn disable_last_modified(self) -> Self {
+        Self {
+            last_modified_enabled: false,
+            ..self
+        }
+    }
+
+    /// Enables negotiating language-tagged sibling files (e.g. `index.html`
+    /// to `index.en.html` or `index.zh-CN.html`) based on `Accept-Language`.
+    ///
+    /// This negotiates through [`fluent_langneg`], the same language
+    /// negotiation used by the [i18n](crate::i18n) layer's `Locale`, so a
+    /// request resolves to the same language a `Locale`-based responder
+    /// would pick. Falls back to the requested path when no tagged sibling
+    /// matches.
+    #[cfg(feature = "i18n")]
+    #[must_use]
+    pub fn with_language_negotiation(self, enable: bool) -> Self {
+        Self {
+            language_negotiation: enable,
+            ..self
+        }
+    }
+
     /// Create static file response.
     ///
     /// `prefer_utf8` - Specifies whether text responses should signal a UTF-8
@@ -45,53 +198,475 @@ impl StaticFile {
     ) -> Result<Response, StaticFileError> {
         let path = path.as_ref();
         let guess = mime_guess::from_path(path);
-        let file = std::fs::File::open(path)?;
+        let guessed_mime = guess.first();
+
+        #[cfg(feature = "i18n")]
+        let (localized_path, content_language) = if self.language_negotiation {
+            match negotiate_language(path, self.accept_language.as_deref()) {
+                Some((variant_path, lang)) => (variant_path, Some(lang)),
+                None => (path.to_path_buf(), None),
+            }
+        } else {
+            (path.to_path_buf(), None)
+        };
+        #[cfg(not(feature = "i18n"))]
+        let (localized_path, content_language): (PathBuf, Option<String>) =
+            (path.to_path_buf(), None);
+
+        let (serve_path, content_encoding) = if self.precompressed {
+            match negotiate_precompressed(&localized_path, self.accept_encoding.as_deref()) {
+                Some((variant_path, encoding)) => (variant_path, Some(encoding)),
+                None => (localized_path, None),
+            }
+        } else {
+            (localized_path, None)
+        };
+
+        let mut file = std::fs::File::open(&serve_path)?;
         let metadata = file.metadata()?;
-        let mut builder = Response::builder();
+        let mut builder = Response::builder().header(header::ACCEPT_RANGES, "bytes");
+
+        let mut vary = Vec::new();
+        if self.precompressed {
+            vary.push("Accept-Encoding");
+        }
+        #[cfg(feature = "i18n")]
+        if self.language_negotiation {
+            vary.push("Accept-Language");
+        }
+        if !vary.is_empty() {
+            builder = builder.header(header::VARY, vary.join(", "));
+        }
+        if let Some(content_encoding) = &content_encoding {
+            builder = builder.header(header::CONTENT_ENCODING, content_encoding.as_str());
+        }
+        if let Some(content_language) = &content_language {
+            builder = builder.header(header::CONTENT_LANGUAGE, content_language.as_str());
+        }
 
         // content type
-        if let Some(mut mime) = guess.first() {
+        if let Some(content_type) = &self.content_type {
+            builder = builder.header(header::CONTENT_TYPE, content_type.to_string());
+        } else if let Some(mut mime) = guessed_mime.clone() {
             if prefer_utf8 {
                 mime = equiv_utf8_text(mime);
             }
             builder = builder.header(header::CONTENT_TYPE, mime.to_string());
         }
 
+        // content disposition
+        if self.content_disposition_type.is_some() || self.attachment_filename.is_some() {
+            let disposition_type = self.content_disposition_type.unwrap_or_else(|| {
+                guessed_mime
+                    .as_ref()
+                    .map(default_disposition_type)
+                    .unwrap_or(ContentDispositionType::Attachment)
+            });
+
+            let mut value = disposition_type.as_str().to_string();
+            if let Some(filename) = &self.attachment_filename {
+                value.push_str("; ");
+                value.push_str(&content_disposition_filename(filename));
+            }
+            builder = builder.header(header::CONTENT_DISPOSITION, value);
+        }
+
+        let mut etag_and_modified = None;
+
         if let Ok(modified) = metadata.modified() {
-            let etag = etag(ino(&metadata), &modified, metadata.len());
+            let etag_value = self
+                .etag_enabled
+                .then(|| etag(ino(&metadata), &modified, metadata.len()));
 
-            if let Some(if_match) = self.if_match {
-                if !if_match.precondition_passes(&etag) {
-                    return Ok(builder.status(StatusCode::PRECONDITION_FAILED).finish());
+            if self.etag_enabled {
+                if let (Some(if_match), Some(etag_value)) = (&self.if_match, &etag_value) {
+                    if !if_match.precondition_passes(etag_value) {
+                        return Ok(builder.status(StatusCode::PRECONDITION_FAILED).finish());
+                    }
                 }
             }
 
-            if let Some(if_unmodified_since) = self.if_unmodified_since {
-                if !if_unmodified_since.precondition_passes(modified) {
-                    return Ok(builder.status(StatusCode::PRECONDITION_FAILED).finish());
+            if self.last_modified_enabled {
+                if let Some(if_unmodified_since) = &self.if_unmodified_since {
+                    if !if_unmodified_since.precondition_passes(modified) {
+                        return Ok(builder.status(StatusCode::PRECONDITION_FAILED).finish());
+                    }
                 }
             }
 
-            if let Some(if_non_match) = self.if_none_match {
-                if !if_non_match.precondition_passes(&etag) {
+            if let (true, Some(if_non_match), Some(etag_value)) =
+                (self.etag_enabled, &self.if_none_match, &etag_value)
+            {
+                if !if_non_match.precondition_passes(etag_value) {
                     return Ok(builder.status(StatusCode::NOT_MODIFIED).finish());
                 }
-            } else if let Some(if_modified_since) = self.if_modified_since {
-                if !if_modified_since.is_modified(modified) {
-                    return Ok(builder.status(StatusCode::NOT_MODIFIED).finish());
+            } else if self.last_modified_enabled {
+                if let Some(if_modified_since) = &self.if_modified_since {
+                    if !if_modified_since.is_modified(modified) {
+                        return Ok(builder.status(StatusCode::NOT_MODIFIED).finish());
+                    }
                 }
             }
 
-            builder = builder
-                .header(header::CACHE_CONTROL, "public")
-                .header(header::LAST_MODIFIED, HttpDate::from(modified).to_string());
-            builder = builder.typed_header(etag);
+            builder = builder.header(
+                header::CACHE_CONTROL,
+                self.cache_control.as_deref().unwrap_or("public"),
+            );
+            if self.last_modified_enabled {
+                builder =
+                    builder.header(header::LAST_MODIFIED, HttpDate::from(modified).to_string());
+            }
+            if let Some(etag_value) = &etag_value {
+                builder = builder.typed_header(etag_value.clone());
+            }
+
+            etag_and_modified = Some((etag_value, modified));
+        }
+
+        // range
+        if let Some(range) = &self.range {
+            let range_applies = match (&self.if_range, &etag_and_modified) {
+                (Some(if_range), Some((etag, modified))) => {
+                    if_range_matches(if_range, etag.as_ref(), *modified)
+                }
+                // Without a modification time there is neither an ETag nor a
+                // Last-Modified to compare against, so play it safe and serve the
+                // full response.
+                (Some(_), None) => false,
+                (None, _) => true,
+            };
+
+            if range_applies {
+                return Ok(match parse_range(range, metadata.len()) {
+                    RangeCheck::Partial(range) => {
+                        file.seek(SeekFrom::Start(range.start))?;
+                        builder
+                            .status(StatusCode::PARTIAL_CONTENT)
+                            .header(
+                                header::CONTENT_RANGE,
+                                format!("bytes {}-{}/{}", range.start, range.end, metadata.len()),
+                            )
+                            .header(header::CONTENT_LENGTH, range.len().to_string())
+                            .body(Body::from_async_read(
+                                File::from_std(file).take(range.len()),
+                            ))
+                    }
+                    RangeCheck::Unsatisfiable => builder
+                        .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                        .header(header::CONTENT_RANGE, format!("bytes */{}", metadata.len()))
+                        .finish(),
+                    RangeCheck::Full => {
+                        if let Some(status) = self.status {
+                            builder = builder.status(status);
+                        }
+                        builder.body(Body::from_async_read(File::from_std(file)))
+                    }
+                });
+            }
         }
 
+        if let Some(status) = self.status {
+            builder = builder.status(status);
+        }
         Ok(builder.body(Body::from_async_read(File::from_std(file))))
     }
 }
 
+/// A single satisfiable byte range, inclusive on both ends.
+#[derive(Debug, Clone, Copy)]
+struct ByteRange {
+    start: u64,
+    end: u64,
+}
+
+impl ByteRange {
+    fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
+/// The result of validating a `Range` header against the file size.
+enum RangeCheck {
+    /// No single range could be extracted, serve the whole file.
+    Full,
+    /// A satisfiable byte range.
+    Partial(ByteRange),
+    /// The requested range falls outside of the file.
+    Unsatisfiable,
+}
+
+/// Parses a `Range: bytes=...` header value.
+///
+/// Only a single range is supported; multiple ranges fall back to a full
+/// response rather than a multipart one.
+fn parse_range(value: &str, len: u64) -> RangeCheck {
+    let value = match value.strip_prefix("bytes=") {
+        Some(value) => value,
+        None => return RangeCheck::Full,
+    };
+
+    if value.contains(',') {
+        return RangeCheck::Full;
+    }
+
+    let (start, end) = match value.split_once('-') {
+        Some(parts) => parts,
+        None => return RangeCheck::Full,
+    };
+
+    if start.is_empty() {
+        // suffix range: `-N` means the last `N` bytes of the file.
+        let suffix_len = match end.parse::<u64>() {
+            Ok(n) => n,
+            Err(_) => return RangeCheck::Full,
+        };
+        return if suffix_len == 0 || len == 0 {
+            RangeCheck::Unsatisfiable
+        } else {
+            RangeCheck::Partial(ByteRange {
+                start: len.saturating_sub(suffix_len),
+                end: len - 1,
+            })
+        };
+    }
+
+    let start = match start.parse::<u64>() {
+        Ok(n) => n,
+        Err(_) => return RangeCheck::Full,
+    };
+    if start >= len {
+        return RangeCheck::Unsatisfiable;
+    }
+
+    let end = if end.is_empty() {
+        len - 1
+    } else {
+        match end.parse::<u64>() {
+            Ok(n) => n.min(len - 1),
+            Err(_) => return RangeCheck::Full,
+        }
+    };
+    if end < start {
+        return RangeCheck::Unsatisfiable;
+    }
+
+    RangeCheck::Partial(ByteRange { start, end })
+}
+
+/// Checks whether an `If-Range` header value still matches the current
+/// representation of the file.
+fn if_range_matches(if_range: &str, etag: Option<&ETag>, modified: SystemTime) -> bool {
+    if let Ok(parsed) = ETag::from_str(if_range) {
+        return etag == Some(&parsed);
+    }
+
+    HttpDate::from_str(if_range)
+        .map(|date| date == HttpDate::from(modified))
+        .unwrap_or(false)
+}
+
+/// Maps a negotiated `Accept-Encoding` token to the file extension used for
+/// its pre-compressed sibling.
+fn precompressed_extension(encoding: &str) -> Option<&'static str> {
+    match encoding {
+        "br" => Some("br"),
+        "gzip" => Some("gz"),
+        "zstd" => Some("zst"),
+        _ => None,
+    }
+}
+
+/// Looks for a pre-compressed sibling of `path` (e.g. `path.br`) that is
+/// acceptable per the client's `Accept-Encoding` header, trying encodings in
+/// the client's own preference order (highest `q` first) rather than a fixed
+/// server-side order.
+fn negotiate_precompressed(path: &Path, accept_encoding: Option<&str>) -> Option<(PathBuf, String)> {
+    let accepted = parse_accept_encoding(accept_encoding?);
+
+    accepted.into_iter().find_map(|encoding| {
+        let ext = precompressed_extension(&encoding)?;
+        let mut variant = path.as_os_str().to_os_string();
+        variant.push(".");
+        variant.push(ext);
+        let variant_path = PathBuf::from(variant);
+        variant_path.is_file().then_some((variant_path, encoding))
+    })
+}
+
+/// Parses an `Accept-Encoding` header into the encodings the client accepts,
+/// sorted by descending `q` preference (ties keep the header's order).
+/// Encodings explicitly disabled with `;q=0` are excluded.
+fn parse_accept_encoding(value: &str) -> Vec<String> {
+    let mut entries: Vec<(String, f32)> = value
+        .split(',')
+        .filter_map(|part| {
+            let mut segments = part.trim().split(';');
+            let token = segments.next()?.trim();
+            if token.is_empty() {
+                return None;
+            }
+
+            let q = segments
+                .next()
+                .and_then(|q| q.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if q <= 0.0 {
+                return None;
+            }
+
+            Some((token.to_ascii_lowercase(), q))
+        })
+        .collect();
+
+    entries.sort_by(|a, b| b.1.total_cmp(&a.1));
+    entries.into_iter().map(|(token, _)| token).collect()
+}
+
+/// Looks for a language-tagged sibling of `path` (e.g. `index.en.html` or
+/// `index.zh-CN.html`) matching the client's `Accept-Language` preferences.
+///
+/// Negotiation goes through [`fluent_langneg::negotiate_languages`] with
+/// [`NegotiationStrategy::Filtering`] — the same matching the
+/// [i18n](crate::i18n) layer's `Locale` uses — so tags are compared as
+/// canonical [`LanguageIdentifier`]s rather than raw strings: this matches
+/// case-insensitively (`zh-cn` resolves to an `index.zh-CN.html` sibling) and
+/// falls back across script/region (e.g. `zh` can resolve to a `zh-CN`
+/// sibling) instead of a naive primary-subtag split. A bare `*` is treated
+/// as "any language is acceptable", resolving to the first available
+/// sibling found on disk.
+#[cfg(feature = "i18n")]
+fn negotiate_language(path: &Path, accept_language: Option<&str>) -> Option<(PathBuf, String)> {
+    let (requested, wildcard) = parse_accept_language(accept_language?);
+
+    let stem = path.file_stem()?.to_str()?;
+    let ext = path.extension().and_then(|ext| ext.to_str());
+    let parent = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let available: Vec<(LanguageIdentifier, PathBuf)> = std::fs::read_dir(parent)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let name = name.to_str()?;
+            let rest = name.strip_prefix(stem)?.strip_prefix('.')?;
+            let tag = match ext {
+                Some(ext) => rest.strip_suffix(&format!(".{ext}"))?,
+                None => rest,
+            };
+            let langid: LanguageIdentifier = tag.parse().ok()?;
+            Some((langid, entry.path()))
+        })
+        .collect();
+
+    if available.is_empty() || (requested.is_empty() && !wildcard) {
+        return None;
+    }
+
+    let available_langids: Vec<LanguageIdentifier> =
+        available.iter().map(|(langid, _)| langid.clone()).collect();
+    let default = wildcard.then(|| available_langids.first()).flatten();
+    let negotiated = negotiate_languages(
+        &requested,
+        &available_langids,
+        default,
+        NegotiationStrategy::Filtering,
+    );
+    let best = *negotiated.first()?;
+
+    available
+        .into_iter()
+        .find(|(langid, _)| langid == best)
+        .map(|(langid, path)| (path, langid.to_string()))
+}
+
+/// Parses an `Accept-Language` header into the requested [`LanguageIdentifier`]s
+/// (sorted by descending `q` preference; tags disabled with `;q=0` are
+/// excluded), plus whether a bare `*` was present.
+#[cfg(feature = "i18n")]
+fn parse_accept_language(value: &str) -> (Vec<LanguageIdentifier>, bool) {
+    let mut wildcard = false;
+    let mut entries: Vec<(LanguageIdentifier, f32)> = value
+        .split(',')
+        .filter_map(|part| {
+            let mut segments = part.trim().split(';');
+            let tag = segments.next()?.trim();
+            if tag.is_empty() {
+                return None;
+            }
+
+            let q = segments
+                .next()
+                .and_then(|q| q.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if q <= 0.0 {
+                return None;
+            }
+
+            if tag == "*" {
+                wildcard = true;
+                return None;
+            }
+
+            let langid: LanguageIdentifier = tag.parse().ok()?;
+            Some((langid, q))
+        })
+        .collect();
+
+    entries.sort_by(|a, b| b.1.total_cmp(&a.1));
+    (entries.into_iter().map(|(langid, _)| langid).collect(), wildcard)
+}
+
+/// Guesses whether a MIME type is commonly viewable in-browser (and should
+/// therefore default to `inline`) or not (and should default to
+/// `attachment`).
+fn default_disposition_type(mime: &Mime) -> ContentDispositionType {
+    match (mime.type_(), mime.subtype()) {
+        (mime::IMAGE, _) => ContentDispositionType::Inline,
+        (mime::TEXT, _) => ContentDispositionType::Inline,
+        (mime::APPLICATION, mime::JAVASCRIPT) => ContentDispositionType::Inline,
+        (mime::APPLICATION, subtype) if subtype == "pdf" => ContentDispositionType::Inline,
+        _ => ContentDispositionType::Attachment,
+    }
+}
+
+/// Builds the `filename=` (and, for non-ASCII names, `filename*=`) portion of
+/// a `Content-Disposition` header, per RFC 5987.
+fn content_disposition_filename(filename: &str) -> String {
+    let ascii_filename: String = filename
+        .chars()
+        .map(|c| if c.is_ascii() && c != '"' && c != '\\' { c } else { '_' })
+        .collect();
+
+    if filename.is_ascii() {
+        format!("filename=\"{ascii_filename}\"")
+    } else {
+        format!(
+            "filename=\"{ascii_filename}\"; filename*=UTF-8''{}",
+            percent_encode_rfc5987(filename)
+        )
+    }
+}
+
+/// Percent-encodes a string per the `attr-char` rule used by RFC 5987's
+/// `ext-value` production.
+fn percent_encode_rfc5987(value: &str) -> String {
+    fn is_attr_char(b: u8) -> bool {
+        b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b)
+    }
+
+    let mut out = String::with_capacity(value.len());
+    for b in value.as_bytes() {
+        if is_attr_char(*b) {
+            out.push(*b as char);
+        } else {
+            out.push_str(&format!("%{b:02X}"));
+        }
+    }
+    out
+}
+
 fn equiv_utf8_text(ct: Mime) -> Mime {
     if ct == mime::APPLICATION_JAVASCRIPT {
         return mime::APPLICATION_JAVASCRIPT_UTF_8;
@@ -276,4 +851,257 @@ mod tests {
         .await;
         assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
     }
+
+    #[tokio::test]
+    async fn test_range() {
+        let full = check_response(Request::default()).await;
+        let len: u64 = full.header("content-length").map_or_else(
+            || std::fs::metadata("Cargo.toml").unwrap().len(),
+            |value| value.parse().unwrap(),
+        );
+
+        let resp = check_response(Request::builder().header("range", "bytes=0-4").finish()).await;
+        assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(resp.header("content-length").unwrap(), "5");
+        assert_eq!(
+            resp.header("content-range").unwrap(),
+            format!("bytes 0-4/{len}")
+        );
+
+        let resp = check_response(Request::builder().header("range", "bytes=-5").finish()).await;
+        assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(
+            resp.header("content-range").unwrap(),
+            format!("bytes {}-{}/{len}", len - 5, len - 1)
+        );
+
+        let resp = check_response(
+            Request::builder()
+                .header("range", format!("bytes={len}-{}", len + 10))
+                .finish(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(
+            resp.header("content-range").unwrap(),
+            format!("bytes */{len}")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_if_range() {
+        let resp = check_response(Request::default()).await;
+        let etag = resp.header("etag").unwrap().to_string();
+
+        let resp = check_response(
+            Request::builder()
+                .header("range", "bytes=0-4")
+                .header("if-range", &etag)
+                .finish(),
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+
+        let resp = check_response(
+            Request::builder()
+                .header("range", "bytes=0-4")
+                .header("if-range", "\"stale\"")
+                .finish(),
+        )
+        .await;
+        assert!(resp.is_ok());
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_content_disposition() {
+        let static_file = StaticFile::from_request_without_body(&Request::default())
+            .await
+            .unwrap();
+        let resp = static_file
+            .with_attachment_filename("résumé.pdf")
+            .create_response(Path::new("Cargo.toml"), false)
+            .unwrap();
+        let disposition = resp.header("content-disposition").unwrap();
+        assert!(disposition.starts_with("attachment; "));
+        assert!(disposition.contains("filename=\"r_sum_.pdf\""));
+        assert!(disposition.contains("filename*=UTF-8''r%C3%A9sum%C3%A9.pdf"));
+
+        let static_file = StaticFile::from_request_without_body(&Request::default())
+            .await
+            .unwrap();
+        let resp = static_file
+            .with_content_disposition_type(ContentDispositionType::Inline)
+            .with_attachment_filename("readme.txt")
+            .create_response(Path::new("Cargo.toml"), false)
+            .unwrap();
+        assert_eq!(
+            resp.header("content-disposition").unwrap(),
+            "inline; filename=\"readme.txt\""
+        );
+    }
+
+    #[test]
+    fn test_parse_accept_encoding() {
+        assert_eq!(parse_accept_encoding("gzip, br"), vec!["gzip", "br"]);
+        assert_eq!(
+            parse_accept_encoding("gzip;q=0, br;q=0.5"),
+            vec!["br"]
+        );
+        assert_eq!(parse_accept_encoding("*"), vec!["*"]);
+    }
+
+    #[tokio::test]
+    async fn test_precompressed() {
+        let dir = std::env::temp_dir().join("poem-static-file-precompressed-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let original = dir.join("app.js");
+        let gz = dir.join("app.js.gz");
+        std::fs::write(&original, b"console.log('hi');").unwrap();
+        std::fs::write(&gz, b"not-really-gzipped-but-thats-fine-for-the-test").unwrap();
+
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder().header("accept-encoding", "gzip").finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_precompressed(true)
+            .create_response(&original, false)
+            .unwrap();
+        assert_eq!(resp.header("content-encoding").unwrap(), "gzip");
+        assert_eq!(resp.header("vary").unwrap(), "Accept-Encoding");
+
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder().header("accept-encoding", "br").finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_precompressed(true)
+            .create_response(&original, false)
+            .unwrap();
+        assert!(resp.header("content-encoding").is_none());
+
+        // When several siblings exist, the client's own preference (`q`)
+        // decides which one is served.
+        let br = dir.join("app.js.br");
+        std::fs::write(&br, b"also-not-really-brotli").unwrap();
+
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder()
+                .header("accept-encoding", "gzip;q=1.0, br;q=0.1")
+                .finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_precompressed(true)
+            .create_response(&original, false)
+            .unwrap();
+        assert_eq!(resp.header("content-encoding").unwrap(), "gzip");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_builder_config() {
+        let static_file = StaticFile::from_request_without_body(&Request::default())
+            .await
+            .unwrap();
+        let resp = static_file
+            .with_content_type(mime::IMAGE_PNG)
+            .with_status(StatusCode::NOT_FOUND)
+            .with_cache_control("public, max-age=31536000, immutable")
+            .create_response(Path::new("Cargo.toml"), false)
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(resp.header("content-type").unwrap(), "image/png");
+        assert_eq!(
+            resp.header("cache-control").unwrap(),
+            "public, max-age=31536000, immutable"
+        );
+        assert!(resp.header("etag").is_some());
+        assert!(resp.header("last-modified").is_some());
+
+        let static_file = StaticFile::from_request_without_body(&Request::default())
+            .await
+            .unwrap();
+        let resp = static_file
+            .disable_etag()
+            .disable_last_modified()
+            .create_response(Path::new("Cargo.toml"), false)
+            .unwrap();
+        assert!(resp.header("etag").is_none());
+        assert!(resp.header("last-modified").is_none());
+    }
+
+    #[cfg(feature = "i18n")]
+    #[tokio::test]
+    async fn test_language_negotiation() {
+        let dir = std::env::temp_dir().join("poem-static-file-language-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let base = dir.join("index.html");
+        let zh_cn = dir.join("index.zh-CN.html");
+        let fr = dir.join("index.fr.html");
+        std::fs::write(&base, b"default").unwrap();
+        std::fs::write(&zh_cn, b"zh-CN").unwrap();
+        std::fs::write(&fr, b"fr").unwrap();
+
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder()
+                .header("accept-language", "zh-CN, en;q=0.8")
+                .finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_language_negotiation(true)
+            .create_response(&base, false)
+            .unwrap();
+        assert_eq!(resp.header("content-language").unwrap(), "zh-CN");
+        assert_eq!(resp.header("vary").unwrap(), "Accept-Language");
+
+        // Matching is case-insensitive and goes through canonical language
+        // identifiers, not a literal string compare.
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder()
+                .header("accept-language", "zh-cn")
+                .finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_language_negotiation(true)
+            .create_response(&base, false)
+            .unwrap();
+        assert_eq!(resp.header("content-language").unwrap(), "zh-CN");
+
+        // A bare primary subtag falls back to an available region variant.
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder().header("accept-language", "zh").finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_language_negotiation(true)
+            .create_response(&base, false)
+            .unwrap();
+        assert_eq!(resp.header("content-language").unwrap(), "zh-CN");
+
+        let static_file = StaticFile::from_request_without_body(
+            &Request::builder()
+                .header("accept-language", "de")
+                .finish(),
+        )
+        .await
+        .unwrap();
+        let resp = static_file
+            .with_language_negotiation(true)
+            .create_response(&base, false)
+            .unwrap();
+        assert!(resp.header("content-language").is_none());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }